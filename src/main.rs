@@ -3,12 +3,22 @@ use anyhow::Result;
 use byte_unit::Byte;
 use clap::arg;
 use clap::Parser;
+use flate2::write::GzEncoder;
+use flate2::Compression as GzCompression;
+use humantime::parse_duration;
 use log::error;
 use log::info;
-use std::fs::rename;
 use std::fs::File;
+use std::fs::OpenOptions;
+use std::io;
 use std::io::stdin;
+use std::io::BufReader;
+use std::io::Read;
 use std::io::Write;
+use std::sync::mpsc;
+use std::sync::mpsc::RecvTimeoutError;
+use std::thread;
+use std::time::Instant;
 
 #[derive(Parser)]
 #[clap(long_about = r#"
@@ -29,7 +39,9 @@ If `file` grows to reach or exceed the `limit` (`-s`), then:
 2. In the range 1..`R` (where `R` is `-r`, `--num-rotate`),
    rename `file.N` to `file.N+1` if `N < R`. (I.e., rotate the files.)
 3. Rename `file` `file.1`
-4. Create or open the specified file and continue writing to it.
+4. If `--compress` is set, compress `file.1` in place (e.g. `file.1.gz`).
+5. If `--postrotate` is set, run it with `FROTS_ROTATED_PATH`/`FROTS_INDEX` in its environment.
+6. Create or open the specified file and continue writing to it.
 
 Example usage:
 ```sh
@@ -40,6 +52,7 @@ some-prog | frots -f /var/log/prog/a.log -s 1G -r 2 --tee -v
 Notes:
 - "Rename" file operations mean "in place" renaming, as-if with `rename()`, not copy-and-move.
 - "Synchronize" file operations mean to-disk synchronization, as-if with `fsync()`.
+- `--interval` rotates on wall-clock age too, even if stdin goes quiet for a while.
 "#)]
 struct Cli {
     /// The name of the file to write to
@@ -57,11 +70,56 @@ struct Cli {
     /// Whether to "tee" stdin to stdout as well as to `file` (just like `tee(1)`)
     #[arg(long, default_value = "false")]
     tee: bool,
+    /// Append to `file` instead of truncating it, counting its existing bytes toward the limit
+    #[arg(short = 'a', long, default_value = "false")]
+    append: bool,
+    /// Compress a file as it rotates out of the active slot (`file.1`)
+    #[arg(long, value_enum, default_value = "none")]
+    compress: Compression,
+    /// Compression level to pass to the encoder chosen by `--compress` (encoder default if unset)
+    #[arg(long)]
+    compress_level: Option<u32>,
+    /// Shell command to run after each rotation; sees `FROTS_ROTATED_PATH` (the file that just
+    /// rotated out of the active slot) and `FROTS_INDEX` (always `1`, that slot's number).
+    /// Not run with `-r 1`, where no file is kept in slot 1
+    #[arg(long)]
+    postrotate: Option<String>,
+    /// Also rotate once the active segment is this old (e.g. "1h", "24h"), whichever comes first
+    #[arg(long)]
+    interval: Option<String>,
     /// Verbose output, as-if by setting `RUST_LOG=info` in the environment
     #[arg(short = 'v', long)]
     verbose: bool,
 }
 
+/// How a file that's just rotated out of the active slot should be stored on disk.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum Compression {
+    None,
+    Gzip,
+    Zstd,
+}
+
+impl Compression {
+    /// The suffix a rotated-and-compressed file carries, e.g. `file.2.gz`.
+    fn suffix(self) -> &'static str {
+        match self {
+            Compression::None => "",
+            Compression::Gzip => ".gz",
+            Compression::Zstd => ".zst",
+        }
+    }
+
+    /// The `--compress-level` range this encoder accepts.
+    fn level_range(self) -> std::ops::RangeInclusive<u32> {
+        match self {
+            Compression::None => 0..=0,
+            Compression::Gzip => 0..=9,
+            Compression::Zstd => 0..=22,
+        }
+    }
+}
+
 fn rot_nr_scheme(num_rotate: u16) -> impl Iterator<Item = (u16, u16)> {
     let xs = 0..num_rotate;
     let ys = 1..=num_rotate;
@@ -71,24 +129,149 @@ fn rot_nr_scheme(num_rotate: u16) -> impl Iterator<Item = (u16, u16)> {
 fn rot_file_scheme(
     file_path: &str,
     num_rotate: u16,
+    compression: Compression,
 ) -> impl Iterator<Item = (String, String)> + '_ {
+    // Slot 1 is special: it's the destination of the plain "active file" rename, and only
+    // picks up `compression`'s suffix *after* `rot` compresses it, in a step of its own.
+    // Every other slot, by the time it's touched here, already carries that suffix on disk.
+    let suffix = compression.suffix();
     let w_file_path = move |(from, to)| {
         let from = match from {
             0 => format!("{file_path}"),
-            n => format!("{file_path}.{n}"),
+            n => format!("{file_path}.{n}{suffix}"),
+        };
+        let to = match to {
+            1 => format!("{file_path}.1"),
+            n => format!("{file_path}.{n}{suffix}"),
         };
-        let to = format!("{file_path}.{to}");
         (from, to)
     };
     rot_nr_scheme(num_rotate).map(w_file_path)
 }
 
-fn rot(file_path: &str, num_rotate: u16) -> Result<File> {
-    for (from, to) in rot_file_scheme(file_path, num_rotate) {
+/// The rename/create/len operations `rot` needs, pulled out so the rotation dance can be
+/// exercised against an in-memory fake instead of a real tempdir full of files.
+/// Compression (`compress_rotated`) does not go through this trait: it reads, writes and
+/// removes files on the real filesystem.
+trait Vfs {
+    type File: Write;
+    fn rename(&self, from: &str, to: &str) -> io::Result<()>;
+    fn create(&self, path: &str) -> io::Result<Self::File>;
+    fn len(&self, path: &str) -> io::Result<u64>;
+}
+
+struct OsVfs;
+
+impl Vfs for OsVfs {
+    type File = File;
+    fn rename(&self, from: &str, to: &str) -> io::Result<()> {
+        std::fs::rename(from, to)
+    }
+    fn create(&self, path: &str) -> io::Result<File> {
+        File::create(path)
+    }
+    fn len(&self, path: &str) -> io::Result<u64> {
+        Ok(std::fs::metadata(path)?.len())
+    }
+}
+
+fn rot<V: Vfs>(
+    vfs: &V,
+    file_path: &str,
+    num_rotate: u16,
+    compression: Compression,
+    compress_level: Option<u32>,
+    postrotate: Option<&str>,
+) -> Result<V::File> {
+    for (from, to) in rot_file_scheme(file_path, num_rotate, compression) {
         info!("Renaming {from} -> {to}");
-        rename(&from, &to)?;
+        vfs.rename(&from, &to)?;
+    }
+    // `num_rotate == 1` renames nothing into slot 1 (there's no history to keep), so
+    // there's nothing on disk at `file_path.1` to compress.
+    if compression != Compression::None && num_rotate >= 2 {
+        compress_rotated(file_path, compression, compress_level)?;
+    }
+    // `num_rotate == 1` renames nothing into slot 1 either (see above), so there's no
+    // rotated file to hand the hook; firing it here would misrepresent what happened.
+    if let (Some(cmd), true) = (postrotate, num_rotate >= 2) {
+        let rotated_path = format!("{file_path}.1{}", compression.suffix());
+        run_postrotate(cmd, &rotated_path, 1);
+    }
+    Ok(vfs.create(file_path)?)
+}
+
+/// Runs the user's `--postrotate` command, surfacing a failure without aborting the stream.
+fn run_postrotate(cmd: &str, rotated_path: &str, index: u16) {
+    let result = std::process::Command::new("sh")
+        .arg("-c")
+        .arg(cmd)
+        .env("FROTS_ROTATED_PATH", rotated_path)
+        .env("FROTS_INDEX", index.to_string())
+        .status();
+    match result {
+        Ok(status) if !status.success() => error!("postrotate command exited with {status}"),
+        Ok(_) => {}
+        Err(e) => error!("Failed to run postrotate command: {e}"),
+    }
+}
+
+/// Compresses the file that just rotated out of the active slot into `file_path.1`,
+/// replacing it with `file_path.1.gz`/`file_path.1.zst`.
+fn compress_rotated(file_path: &str, compression: Compression, level: Option<u32>) -> Result<()> {
+    let rotated = format!("{file_path}.1");
+    let compressed = format!("{rotated}{}", compression.suffix());
+    let mut src = File::open(&rotated)?;
+    let dst = File::create(&compressed)?;
+    match compression {
+        Compression::None => return Ok(()),
+        Compression::Gzip => {
+            let level = level.map(GzCompression::new).unwrap_or_default();
+            let mut encoder = GzEncoder::new(dst, level);
+            io::copy(&mut src, &mut encoder)?;
+            encoder.finish()?;
+        }
+        Compression::Zstd => {
+            zstd::stream::copy_encode(&mut src, dst, level.unwrap_or(0) as i32)?;
+        }
+    }
+    std::fs::remove_file(&rotated)?;
+    Ok(())
+}
+
+/// What the `--interval` loop should do after one poll of the reader-thread channel,
+/// split out of `main` so the size-vs-age rotation decision can be exercised without a
+/// real stdin thread or a real timeout.
+enum IntervalTick {
+    /// Stdin hit EOF (or the reader thread went away); the loop should return.
+    Eof,
+    /// `bytes` arrived; rotate first (for `rotate_reason`) if they bring `file_sz` to or
+    /// over the limit, then write `bytes` to the (possibly just-rotated) file.
+    Data { bytes: Vec<u8>, rotate_reason: Option<String> },
+    /// The segment aged out with no data pending; rotate before the next poll.
+    Aged(String),
+}
+
+fn interval_tick(
+    recv: Result<io::Result<Vec<u8>>, RecvTimeoutError>,
+    file_sz: usize,
+    file_sz_lim: usize,
+    interval: std::time::Duration,
+) -> Result<IntervalTick> {
+    match recv {
+        Ok(Err(e)) => Err(e.into()),
+        Ok(Ok(bytes)) if bytes.is_empty() => Ok(IntervalTick::Eof),
+        Ok(Ok(bytes)) => {
+            let would_be_sz = file_sz + bytes.len();
+            let rotate_reason = (would_be_sz >= file_sz_lim)
+                .then(|| format!("sz={would_be_sz} >= lim={file_sz_lim}"));
+            Ok(IntervalTick::Data { bytes, rotate_reason })
+        }
+        Err(RecvTimeoutError::Timeout) => {
+            Ok(IntervalTick::Aged(format!("age >= interval={interval:?}")))
+        }
+        Err(RecvTimeoutError::Disconnected) => Ok(IntervalTick::Eof),
     }
-    Ok(File::create(file_path)?)
 }
 
 fn main() -> Result<()> {
@@ -102,40 +285,226 @@ fn main() -> Result<()> {
         0 => return Err(anyhow!("`num_rotate` must be >= 1")),
         n => n,
     };
+    if let Some(level) = args.compress_level {
+        let range = args.compress.level_range();
+        if !range.contains(&level) {
+            return Err(anyhow!(
+                "`compress_level` {level} out of range for {:?} (expected {range:?})",
+                args.compress
+            ));
+        }
+    }
+    let interval = args.interval.as_deref().map(parse_duration).transpose()?;
     let file_path = &args.file_path; // Or we write &args.file_path a lot and fmts are weird.
-    let mut file = File::create(file_path)?;
-    let mut file_sz = 0;
-    let mut buf = String::with_capacity(4096);
+    let mut file = if args.append {
+        OpenOptions::new().create(true).append(true).open(file_path)?
+    } else {
+        File::create(file_path)?
+    };
+    let mut file_sz = OsVfs.len(file_path)? as usize;
     info!(
         "Rotation scheme: [(from, to),...] {:?}",
-        rot_file_scheme(file_path, num_rotate).collect::<Vec<_>>()
+        rot_file_scheme(file_path, num_rotate, args.compress).collect::<Vec<_>>()
     );
-    if file.metadata()?.len() as usize > file_sz_lim {
+    if file_sz >= file_sz_lim {
         info!("Rotating (initial sz >= lim={file_sz_lim}, R={num_rotate})");
-        rot(file_path, num_rotate)?;
+        file = rot(
+            &OsVfs,
+            file_path,
+            num_rotate,
+            args.compress,
+            args.compress_level,
+            args.postrotate.as_deref(),
+        )?;
+        file_sz = 0;
     }
-    loop {
-        let n = stdin().read_line(&mut buf)?;
-        file_sz += n;
-        if n == 0 {
-            return Ok(()); // EOF
+    // Rotates `file` in place for a size- or age-triggered reason; resets `file_sz` either way.
+    let rotate = |file: &mut File, file_sz: &mut usize, reason: &str| -> Result<()> {
+        info!("Rotating ({reason}, R={num_rotate})");
+        *file_sz = 0;
+        if let Err(e) = file.sync_all() {
+            error!("Error syncing file w/ disk: {e}");
         }
-        if args.tee {
-            print!("{buf}");
+        *file = rot(
+            &OsVfs,
+            file_path,
+            num_rotate,
+            args.compress,
+            args.compress_level,
+            args.postrotate.as_deref(),
+        )?;
+        Ok(())
+    };
+    match interval {
+        None => {
+            let mut reader = BufReader::new(stdin());
+            let mut buf = [0u8; 4096];
+            loop {
+                let n = reader.read(&mut buf)?;
+                file_sz += n;
+                if n == 0 {
+                    return Ok(()); // EOF
+                }
+                let bytes = &buf[..n];
+                if args.tee {
+                    std::io::stdout().write_all(bytes)?;
+                }
+                if file_sz >= file_sz_lim {
+                    let reason = format!("sz={file_sz} >= lim={file_sz_lim}");
+                    rotate(&mut file, &mut file_sz, &reason)?;
+                }
+                // Written after a possible rotation above, so bytes land in the new file.
+                file.write_all(bytes)?;
+            }
         }
-        if file_sz >= file_sz_lim {
-            info!("Rotating (sz={file_sz} >= lim={file_sz_lim}, R={num_rotate})");
-            file_sz = 0;
-            if let Err(e) = file.sync_all() {
-                error!("Error syncing file w/ disk: {e}");
+        Some(interval) => {
+            // Stdin is read on its own thread and handed over on a rendezvous channel so the
+            // main loop can still wake on a timeout and rotate an idle-but-too-old segment;
+            // a plain blocking `read` on this thread would never notice the age elapsing.
+            let (tx, rx) = mpsc::sync_channel::<io::Result<Vec<u8>>>(0);
+            thread::spawn(move || {
+                let mut reader = BufReader::new(stdin());
+                let mut buf = [0u8; 4096];
+                loop {
+                    match reader.read(&mut buf) {
+                        Ok(0) => {
+                            let _ = tx.send(Ok(Vec::new())); // EOF sentinel
+                            return;
+                        }
+                        Ok(n) => {
+                            if tx.send(Ok(buf[..n].to_vec())).is_err() {
+                                return;
+                            }
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Err(e));
+                            return;
+                        }
+                    }
+                }
+            });
+            let mut segment_started = Instant::now();
+            loop {
+                let remaining = interval.saturating_sub(segment_started.elapsed());
+                match interval_tick(rx.recv_timeout(remaining), file_sz, file_sz_lim, interval)? {
+                    IntervalTick::Eof => return Ok(()),
+                    IntervalTick::Data { bytes, rotate_reason } => {
+                        if args.tee {
+                            std::io::stdout().write_all(&bytes)?;
+                        }
+                        file_sz += bytes.len();
+                        if let Some(reason) = rotate_reason {
+                            rotate(&mut file, &mut file_sz, &reason)?;
+                            segment_started = Instant::now();
+                        }
+                        file.write_all(&bytes)?;
+                    }
+                    IntervalTick::Aged(reason) => {
+                        rotate(&mut file, &mut file_sz, &reason)?;
+                        segment_started = Instant::now();
+                    }
+                }
             }
-            file = rot(file_path, num_rotate)?;
         }
-        file.write_all(buf.as_bytes())?;
-        buf.clear();
     }
 }
 
+#[cfg(test)]
+struct MemVfs {
+    files: std::cell::RefCell<std::collections::HashMap<String, u64>>,
+}
+
+#[cfg(test)]
+impl MemVfs {
+    fn new(files: &[(&str, u64)]) -> Self {
+        let files = files.iter().map(|(p, sz)| (p.to_string(), *sz)).collect();
+        Self {
+            files: std::cell::RefCell::new(files),
+        }
+    }
+}
+
+#[cfg(test)]
+impl Vfs for MemVfs {
+    type File = io::Sink;
+    fn rename(&self, from: &str, to: &str) -> io::Result<()> {
+        let mut files = self.files.borrow_mut();
+        let sz = files
+            .remove(from)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, from.to_string()))?;
+        files.insert(to.to_string(), sz);
+        Ok(())
+    }
+    fn create(&self, path: &str) -> io::Result<io::Sink> {
+        self.files.borrow_mut().insert(path.to_string(), 0);
+        Ok(io::sink())
+    }
+    fn len(&self, path: &str) -> io::Result<u64> {
+        self.files
+            .borrow()
+            .get(path)
+            .copied()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, path.to_string()))
+    }
+}
+
+#[test]
+#[cfg(test)]
+fn test_rot_effects() {
+    let vfs = MemVfs::new(&[("f", 100), ("f.1", 50)]);
+    rot(&vfs, "f", 3, Compression::None, None, None).unwrap();
+    assert_eq!(vfs.len("f").unwrap(), 0);
+    assert_eq!(vfs.len("f.1").unwrap(), 100);
+    assert_eq!(vfs.len("f.2").unwrap(), 50);
+}
+
+#[test]
+#[cfg(test)]
+fn test_rot_initial_size_already_over_limit() {
+    // Mirrors main()'s pre-loop check: a file already at/over the limit (e.g. from
+    // --append against a pre-existing log) should still rotate cleanly.
+    let vfs = MemVfs::new(&[("f", 9001)]);
+    rot(&vfs, "f", 2, Compression::None, None, None).unwrap();
+    assert_eq!(vfs.len("f").unwrap(), 0);
+    assert_eq!(vfs.len("f.1").unwrap(), 9001);
+}
+
+#[test]
+#[cfg(test)]
+fn test_rot_single_slot_skips_compress() {
+    // `num_rotate == 1` never renames anything into `f.1`, so `rot` must not try to
+    // compress a file that was never created (it previously crashed on `NotFound`).
+    let vfs = MemVfs::new(&[("f", 9001)]);
+    rot(&vfs, "f", 1, Compression::Gzip, None, None).unwrap();
+    assert_eq!(vfs.len("f").unwrap(), 0);
+}
+
+#[test]
+#[cfg(test)]
+fn test_rot_single_slot_skips_postrotate() {
+    // `num_rotate == 1` never renames anything into `f.1`, so `rot` must not fire the
+    // postrotate hook either: there's no rotated file to report, and doing so would
+    // falsely claim a rotation happened.
+    let out_path = std::env::temp_dir().join(format!(
+        "frots_test_single_slot_postrotate_{}",
+        std::process::id()
+    ));
+    let cmd = format!("touch {}", out_path.display());
+    let vfs = MemVfs::new(&[("f", 9001)]);
+    rot(&vfs, "f", 1, Compression::None, None, Some(&cmd)).unwrap();
+    assert_eq!(vfs.len("f").unwrap(), 0);
+    assert!(!out_path.exists());
+}
+
+#[test]
+#[cfg(test)]
+fn test_rot_missing_intermediate_file() {
+    // "f.1" doesn't exist, so renaming it to "f.2" should fail before "f" is touched.
+    let vfs = MemVfs::new(&[("f", 10)]);
+    assert!(rot(&vfs, "f", 3, Compression::None, None, None).is_err());
+    assert_eq!(vfs.len("f").unwrap(), 10);
+}
+
 #[test]
 #[cfg(test)]
 fn test_rot() {
@@ -143,14 +512,103 @@ fn test_rot() {
     assert_eq!(rot_nr_scheme(1).collect::<Vec<_>>(), vec![]);
     assert_eq!(rot_nr_scheme(2).collect::<Vec<_>>(), vec![(0, 1)]);
     assert_eq!(rot_nr_scheme(3).collect::<Vec<_>>(), vec![(1, 2), (0, 1)]);
-    assert_eq!(rot_file_scheme("f", 0).collect::<Vec<_>>(), vec![]);
-    assert_eq!(rot_file_scheme("f", 1).collect::<Vec<_>>(), vec![]);
     assert_eq!(
-        rot_file_scheme("f", 2).collect::<Vec<_>>(),
+        rot_file_scheme("f", 0, Compression::None).collect::<Vec<_>>(),
+        vec![]
+    );
+    assert_eq!(
+        rot_file_scheme("f", 1, Compression::None).collect::<Vec<_>>(),
+        vec![]
+    );
+    assert_eq!(
+        rot_file_scheme("f", 2, Compression::None).collect::<Vec<_>>(),
         vec![("f".into(), "f.1".into())]
     );
     assert_eq!(
-        rot_file_scheme("f", 3).collect::<Vec<_>>(),
+        rot_file_scheme("f", 3, Compression::None).collect::<Vec<_>>(),
         vec![("f.1".into(), "f.2".into()), ("f".into(), "f.1".into())]
     );
 }
+
+#[test]
+#[cfg(test)]
+fn test_interval_tick() {
+    let dur = std::time::Duration::from_secs(60);
+    // EOF sentinel (empty bytes) and a dropped sender both end the loop.
+    assert!(matches!(
+        interval_tick(Ok(Ok(Vec::new())), 0, 10, dur).unwrap(),
+        IntervalTick::Eof
+    ));
+    assert!(matches!(
+        interval_tick(Err(RecvTimeoutError::Disconnected), 0, 10, dur).unwrap(),
+        IntervalTick::Eof
+    ));
+    // A real I/O error from the reader thread propagates as an error, not a silent EOF.
+    assert!(interval_tick(
+        Ok(Err(io::Error::new(io::ErrorKind::Other, "boom"))),
+        0,
+        10,
+        dur
+    )
+    .is_err());
+    // Data under the limit: no rotation.
+    match interval_tick(Ok(Ok(vec![1, 2, 3])), 0, 10, dur).unwrap() {
+        IntervalTick::Data { bytes, rotate_reason } => {
+            assert_eq!(bytes, vec![1, 2, 3]);
+            assert!(rotate_reason.is_none());
+        }
+        _ => panic!("expected Data"),
+    }
+    // Data that reaches the limit: rotation requested.
+    match interval_tick(Ok(Ok(vec![1, 2, 3])), 8, 10, dur).unwrap() {
+        IntervalTick::Data { bytes, rotate_reason } => {
+            assert_eq!(bytes, vec![1, 2, 3]);
+            assert!(rotate_reason.unwrap().contains("sz="));
+        }
+        _ => panic!("expected Data"),
+    }
+    // A timeout always rotates for age, regardless of size.
+    assert!(matches!(
+        interval_tick(Err(RecvTimeoutError::Timeout), 0, 10, dur).unwrap(),
+        IntervalTick::Aged(reason) if reason.contains("age")
+    ));
+}
+
+#[test]
+#[cfg(test)]
+fn test_run_postrotate_exposes_env_vars() {
+    let out_path = std::env::temp_dir().join(format!("frots_test_postrotate_{}", std::process::id()));
+    let cmd = format!(
+        "printf '%s %s' \"$FROTS_ROTATED_PATH\" \"$FROTS_INDEX\" > {}",
+        out_path.display()
+    );
+    run_postrotate(&cmd, "f.1.gz", 1);
+    assert_eq!(std::fs::read_to_string(&out_path).unwrap(), "f.1.gz 1");
+    std::fs::remove_file(&out_path).unwrap();
+}
+
+#[test]
+#[cfg(test)]
+fn test_rot_file_scheme_compressed_suffix() {
+    // Slot 1 is mid-flight (renamed, not yet compressed), so it stays bare; everything
+    // shifting out of a higher slot already carries the compressed suffix on disk.
+    assert_eq!(
+        rot_file_scheme("f", 2, Compression::Gzip).collect::<Vec<_>>(),
+        vec![("f".into(), "f.1".into())]
+    );
+    assert_eq!(
+        rot_file_scheme("f", 3, Compression::Gzip).collect::<Vec<_>>(),
+        vec![
+            ("f.1.gz".into(), "f.2.gz".into()),
+            ("f".into(), "f.1".into())
+        ]
+    );
+    assert_eq!(
+        rot_file_scheme("f", 4, Compression::Zstd).collect::<Vec<_>>(),
+        vec![
+            ("f.2.zst".into(), "f.3.zst".into()),
+            ("f.1.zst".into(), "f.2.zst".into()),
+            ("f".into(), "f.1".into())
+        ]
+    );
+}